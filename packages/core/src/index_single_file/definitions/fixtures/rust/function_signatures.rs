@@ -0,0 +1,55 @@
+/*!
+ * Rust function-signature rendering fixture
+ * Exercises every qualifier/binder the signature printer must reproduce:
+ * generic params, where-clause bounds, const generics, async/unsafe/extern, and
+ * HRTB `for<'a>` quantifiers. The expected rendering is a formatted string of the
+ * form `fn(param_type, param_type) -> ReturnType`.
+ */
+
+use std::fmt::Debug;
+
+// fn(&str, i32) -> String
+fn function_with_params(param1: &str, param2: i32) -> String {
+    format!("{}: {}", param1, param2)
+}
+
+// fn<T: Clone + Debug>(T) -> T
+fn constrained_generic<T: Clone + Debug>(value: T) -> T {
+    value
+}
+
+// fn<const N: usize>([u8; N]) -> usize
+fn const_generic_function<const N: usize>(buffer: [u8; N]) -> usize {
+    buffer.len()
+}
+
+// fn(for<'a> Fn(&'a str) -> &'a str) -> String
+fn higher_ranked_function<F>(f: F) -> String
+where
+    F: for<'a> Fn(&'a str) -> &'a str,
+{
+    f("x").to_string()
+}
+
+// async fn(u32) -> u32
+async fn async_signature(n: u32) -> u32 {
+    n + 1
+}
+
+// unsafe fn(*const i32) -> i32
+unsafe fn unsafe_signature(ptr: *const i32) -> i32 {
+    *ptr
+}
+
+// extern "C" fn(i32, i32) -> i32
+extern "C" fn extern_signature(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+// fn<T>(T) -> T where T: Default + Clone
+fn where_clause_signature<T>(value: T) -> T
+where
+    T: Default + Clone,
+{
+    value
+}
@@ -0,0 +1,50 @@
+/*!
+ * Item-attribute extraction fixture
+ * Each definition should carry structured attributes: `#[deprecated]` (since +
+ * note + level), `#[must_use]`, doc text (`///` and `#[doc = "..."]`), and parsed
+ * `#[cfg(...)]` predicates represented as a boolean-expression tree over
+ * feature/target/test atoms.
+ */
+
+#[deprecated(since = "1.2.0", note = "use `replacement` instead")]
+pub fn deprecated_function() -> i32 {
+    0
+}
+
+/// A function documented with a line doc comment.
+/// The rendered doc text spans both lines.
+pub fn documented_function() -> i32 {
+    1
+}
+
+#[doc = "A function documented via the `#[doc = ...]` attribute form."]
+pub fn attribute_documented_function() -> i32 {
+    2
+}
+
+#[must_use]
+pub fn must_use_function() -> i32 {
+    3
+}
+
+// cfg predicate: feature("advanced")
+#[cfg(feature = "advanced")]
+pub fn advanced_only() -> i32 {
+    4
+}
+
+// cfg predicate: all(feature("async"), feature("networking"))
+#[cfg(all(feature = "async", feature = "networking"))]
+pub mod async_networking {
+    pub fn connect() -> i32 {
+        5
+    }
+}
+
+// Combined attributes on a single item.
+#[must_use]
+#[deprecated(note = "no longer maintained")]
+/// Legacy helper retained for compatibility.
+pub fn combined_attributes() -> i32 {
+    6
+}
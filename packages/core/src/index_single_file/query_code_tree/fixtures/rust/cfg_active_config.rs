@@ -0,0 +1,53 @@
+//! Test fixture for `#[cfg]`/feature-gate evaluation with an active-config set.
+//! Gated items (`ExperimentalStruct`, `NoStdPhantom`, `PermissionsExt`) and
+//! `#[cfg(test)]`-differing impls should be indexed against a user-supplied active
+//! configuration, so false predicates drop phantom definitions and duplicate
+//! resolutions instead of appearing unconditionally.
+
+#[cfg(feature = "experimental")]
+pub struct ExperimentalStruct {
+    pub value: i32,
+}
+
+#[cfg(not(feature = "std"))]
+pub struct NoStdPhantom;
+
+#[cfg(all(unix, target_arch = "x86_64"))]
+pub trait PermissionsExt {
+    fn mode(&self) -> u32;
+}
+
+// Two impls of `new` under complementary `#[cfg(test)]` gates — exactly one is
+// active per config.
+pub struct Counter {
+    count: u32,
+}
+
+#[cfg(not(test))]
+impl Counter {
+    pub fn new() -> Self {
+        Counter { count: 0 }
+    }
+}
+
+#[cfg(test)]
+impl Counter {
+    pub fn new() -> Self {
+        Counter { count: 1000 }
+    }
+}
+
+// A macro body with a cfg-gated statement.
+macro_rules! debug_print {
+    ($e:expr) => {{
+        #[cfg(debug_assertions)]
+        {
+            println!("{:?}", $e);
+        }
+        $e
+    }};
+}
+
+pub fn use_debug_print() -> i32 {
+    debug_print!(42)
+}
@@ -0,0 +1,53 @@
+//! Test fixture for a feature-set-conditional symbol graph via cfg evaluation.
+//! Given a chosen feature set, each `#[cfg(...)]` predicate on modules/items is
+//! evaluated; symbols (and their re-exports) whose predicate is false are excluded
+//! so a call-graph/import-path query reflects exactly what compiles. Exclusion
+//! propagates transitively (an excluded module excludes its children).
+
+// ==============================================================================
+// CONDITIONAL MODULES
+// ==============================================================================
+
+#[cfg(feature = "advanced")]
+pub mod advanced_features {
+    pub fn advanced_op() -> i32 {
+        1
+    }
+
+    // Nested item inherits the module's exclusion when `advanced` is off.
+    pub fn advanced_helper() -> i32 {
+        advanced_op() + 1
+    }
+}
+
+#[cfg(feature = "experimental")]
+pub mod experimental {
+    pub struct ExperimentalStruct;
+}
+
+#[cfg(all(feature = "async", feature = "networking"))]
+pub mod async_networking {
+    pub fn connect() -> i32 {
+        2
+    }
+}
+
+// ==============================================================================
+// CONDITIONAL RE-EXPORTS
+// ==============================================================================
+
+// The re-export disappears when `advanced` is not in the active set.
+#[cfg(feature = "advanced")]
+pub use advanced_features::advanced_op as exposed_private;
+
+// Always-present entry point that references gated items only under their cfg.
+pub fn entry() -> i32 {
+    #[cfg(feature = "advanced")]
+    {
+        return advanced_features::advanced_helper();
+    }
+    #[cfg(not(feature = "advanced"))]
+    {
+        0
+    }
+}
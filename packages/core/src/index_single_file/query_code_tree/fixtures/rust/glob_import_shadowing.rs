@@ -0,0 +1,58 @@
+//! Test fixture for glob import and wildcard re-export resolution with shadowing.
+//! Stresses `pub(super) use self::level2::*` (inside `level1`) and
+//! `use crate::level1::level2::*`. Glob resolution enumerates the public members of the target
+//! module (honoring each member's visibility relative to the importer) and applies
+//! Rust shadowing: an explicit/named import or a local definition wins over a
+//! glob-imported name of the same identifier.
+
+pub fn shared_name() -> i32 {
+    1
+}
+
+pub mod level1 {
+    pub mod level2 {
+        pub fn helper() -> i32 {
+            10
+        }
+
+        // Same identifier as the crate-root `shared_name` -> shadowing candidate.
+        pub fn shared_name() -> i32 {
+            2
+        }
+    }
+
+    // Re-export the inner module's members one level up.
+    pub(super) use self::level2::*;
+}
+
+pub mod consumer {
+    // Glob import brings `helper` and `shared_name` into scope.
+    use crate::level1::level2::*;
+
+    // Explicit/named import of the crate-root `shared_name` shadows the glob one.
+    use crate::shared_name;
+
+    pub fn call_helper() -> i32 {
+        // `helper` comes from the glob.
+        helper()
+    }
+
+    pub fn which_shared() -> i32 {
+        // Resolves to the explicitly-imported crate-root `shared_name`, not the
+        // glob-imported `level2::shared_name`.
+        shared_name()
+    }
+}
+
+pub mod local_shadows {
+    use crate::level1::level2::*;
+
+    // A local definition shadows the glob-imported name of the same identifier.
+    fn shared_name() -> i32 {
+        99
+    }
+
+    pub fn which() -> i32 {
+        shared_name()
+    }
+}
@@ -0,0 +1,48 @@
+//! Test fixture for minimize-visibility analysis of over-exposed `pub` items
+//! Every item declared more publicly than it is actually reachable should be
+//! flaggable with the narrowest visibility that still satisfies its use sites.
+
+// ==============================================================================
+// PUBLIC API SURFACE (legitimately reachable from outside the crate)
+// ==============================================================================
+
+pub fn public_entry() -> i32 {
+    internal_function() + CrateStruct::new().value()
+}
+
+pub use self::inner::ReExported;
+
+// ==============================================================================
+// OVER-EXPOSED ITEMS (declared `pub`, never reachable from outside the crate)
+// ==============================================================================
+
+// Only called from `public_entry` within this crate -> should be `pub(crate)`.
+pub fn internal_function() -> i32 {
+    42
+}
+
+// Constructed only inside this crate -> should be `pub(crate)`.
+pub struct CrateStruct {
+    value: i32,
+}
+
+impl CrateStruct {
+    // Used only by `public_entry` in this same module -> could be private.
+    pub fn new() -> Self {
+        CrateStruct { value: 7 }
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+}
+
+mod inner {
+    // Genuinely re-exported, so `pub` here is justified.
+    pub struct ReExported;
+
+    // Never referenced outside `inner` -> should be private.
+    pub fn never_used_outside() -> u8 {
+        0
+    }
+}
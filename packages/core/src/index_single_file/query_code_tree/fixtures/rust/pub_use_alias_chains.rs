@@ -0,0 +1,41 @@
+//! Test fixture for `pub use` alias-chain resolution and shortest-public-path.
+//! Multi-hop re-exports: `level3::deeply_nested_function` is re-exported as
+//! `level1_fn` then surfaced at the crate root; `DeepStruct` travels through
+//! `Level2Struct` -> `CrateStruct`. References to `public_deep`/`final_a_function`
+//! resolve to the original item, collapsing the alias hops.
+
+pub mod level1 {
+    pub mod level2 {
+        pub mod level3 {
+            pub fn deeply_nested_function() -> i32 {
+                1
+            }
+
+            pub struct DeepStruct {
+                pub value: i32,
+            }
+
+            pub fn final_a_function() -> i32 {
+                2
+            }
+        }
+
+        // Re-export the struct one level up.
+        pub use self::level3::DeepStruct as Level2Struct;
+    }
+
+    // Re-export the function up to level1.
+    pub use self::level2::level3::deeply_nested_function as level1_fn;
+    // Re-export the struct up to level1.
+    pub use self::level2::Level2Struct as CrateStruct;
+}
+
+// Crate-root surfacing — the shortest public paths.
+pub use level1::level1_fn as public_deep;
+pub use level1::CrateStruct;
+pub use level1::level2::level3::final_a_function;
+
+pub fn consume() -> i32 {
+    let s = CrateStruct { value: 3 };
+    public_deep() + final_a_function() + s.value
+}
@@ -0,0 +1,35 @@
+//! Test fixture for re-export chain resolution in go-to-definition.
+//! A name is followed through arbitrarily long `pub use` alias chains to the
+//! single underlying definition, recording each intermediate alias hop so callers
+//! can show the re-export trail. Cycles must be detected and broken.
+
+mod private_inline {
+    pub fn crate_function() -> i32 {
+        1
+    }
+
+    pub fn internal_function() -> i32 {
+        2
+    }
+}
+
+// Alias chain: exposed_crate_function -> crate_function (sink).
+pub use private_inline::crate_function as exposed_crate_function;
+
+// Multi-hop: api_function -> self_internal -> internal_function (sink).
+pub use self::reexports::self_internal as api_function;
+
+mod reexports {
+    pub use crate::private_inline::internal_function as self_internal;
+}
+
+// A prelude that re-exports several names; resolution collapses them to sources.
+pub mod prelude {
+    pub use crate::exposed_crate_function;
+    pub use crate::api_function;
+}
+
+pub fn use_reexports() -> i32 {
+    // Each call resolves through the alias chain to the original `fn` definition.
+    exposed_crate_function() + api_function()
+}
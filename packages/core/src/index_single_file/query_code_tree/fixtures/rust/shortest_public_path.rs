@@ -0,0 +1,42 @@
+//! Test fixture for shortest public import-path computation.
+//! Given a deep definition (`complex::nested::DeepStruct`) and a "from" module,
+//! the API returns the shortest importable path honoring `pub use` re-exports and
+//! aliases, preferring a short re-export over the canonical path.
+
+pub mod complex {
+    // Re-export the deep item at this (shallower) level.
+    pub use self::nested::DeepStruct as PublicDeepStruct;
+
+    // Also surface it via a free function alias path at crate-adjacent depth.
+    pub use self::nested::deep_fn as root_deep_fn;
+
+    pub mod nested {
+        pub struct DeepStruct {
+            pub value: i32,
+        }
+
+        impl DeepStruct {
+            pub fn new() -> Self {
+                DeepStruct { value: 0 }
+            }
+        }
+
+        pub fn deep_fn() -> i32 {
+            7
+        }
+    }
+}
+
+// Crate-root re-export: the shortest path of all for external consumers.
+pub use complex::PublicDeepStruct;
+
+// A query module from which shortest paths are computed.
+pub mod consumer {
+    // The canonical path `crate::complex::nested::DeepStruct` is longer than the
+    // re-exported `crate::complex::PublicDeepStruct` or the root `PublicDeepStruct`.
+    use crate::PublicDeepStruct;
+
+    pub fn make() -> PublicDeepStruct {
+        PublicDeepStruct::new()
+    }
+}
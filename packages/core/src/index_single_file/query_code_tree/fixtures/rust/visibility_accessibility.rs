@@ -0,0 +1,61 @@
+//! Test fixture for visibility-aware reference resolution and accessibility.
+//! Exercises the full visibility lattice — `pub(self)`, `pub(super)`,
+//! `pub(crate)`, `pub(in crate::public_parent)`, and private — on functions,
+//! structs, fields, consts and impl methods. Each definition resolves to the concrete
+//! module node bounding its reachability for an `is_accessible_from(module)` check.
+
+pub mod public_parent {
+    pub const PARENT_CONST: i32 = 1;
+
+    pub mod child {
+        // Visible only within `child`.
+        pub(self) fn self_only() -> i32 {
+            2
+        }
+
+        // Visible within `public_parent` and below.
+        pub(super) fn super_only() -> i32 {
+            self_only()
+        }
+
+        // Visible crate-wide.
+        pub(crate) fn crate_wide() -> i32 {
+            3
+        }
+
+        // Visible within the named ancestor module path.
+        pub(in crate::public_parent) fn scoped_in_path() -> i32 {
+            4
+        }
+
+        pub struct Record {
+            pub visible_field: i32,
+            pub(crate) crate_field: i32,
+            private_field: i32,
+        }
+
+        impl Record {
+            pub fn new() -> Self {
+                Record {
+                    visible_field: 0,
+                    crate_field: 0,
+                    private_field: 0,
+                }
+            }
+
+            pub(super) fn sum(&self) -> i32 {
+                self.visible_field + self.crate_field + self.private_field
+            }
+        }
+    }
+
+    // Reachable: `super_only`/`scoped_in_path` are visible from here.
+    pub fn aggregate() -> i32 {
+        child::super_only() + child::scoped_in_path() + child::crate_wide()
+    }
+}
+
+pub fn crate_root_use() -> i32 {
+    // `crate_wide` is accessible; `self_only` would NOT be from here.
+    public_parent::child::crate_wide() + public_parent::PARENT_CONST
+}
@@ -0,0 +1,64 @@
+// Test fixture for associated-type / associated-const / GAT reference resolution.
+//
+// Projection paths `Self::Item`, `Self::Iterator`, `Self::Entry<'a>`,
+// `Self::Output<U>` connect back to the associated-item
+// declarations in the trait; associated-const refs `C::DEFAULT_CAPACITY` resolve
+// to the `const DEFAULT_CAPACITY` decl. In impls the concrete `type Item = T;` /
+// `type Output<U> = Vec<U>;` assignments are definitions of the trait's items.
+
+pub trait Container {
+    type Item;
+    type Iterator;
+    const DEFAULT_CAPACITY: usize;
+
+    fn first(&self) -> Option<Self::Item>;
+    fn iter(&self) -> Self::Iterator;
+}
+
+pub trait Storage {
+    type IntoIter;
+    // GAT: associated type with a lifetime parameter.
+    type Entry<'a>
+    where
+        Self: 'a;
+
+    fn entry<'a>(&'a self, key: &str) -> Self::Entry<'a>;
+}
+
+// GAT trait with a type-parameterized associated type.
+pub trait Collect {
+    type Output<U>;
+
+    fn collect_into<U>(&self, seed: U) -> Self::Output<U>;
+}
+
+pub struct VecContainer<T> {
+    items: Vec<T>,
+}
+
+impl<T: Clone> Container for VecContainer<T> {
+    type Item = T;
+    type Iterator = std::vec::IntoIter<T>;
+    const DEFAULT_CAPACITY: usize = 16;
+
+    fn first(&self) -> Option<Self::Item> {
+        self.items.first().cloned()
+    }
+
+    fn iter(&self) -> Self::Iterator {
+        self.items.clone().into_iter()
+    }
+}
+
+impl<T> Collect for VecContainer<T> {
+    type Output<U> = Vec<U>;
+
+    fn collect_into<U>(&self, seed: U) -> Self::Output<U> {
+        vec![seed]
+    }
+}
+
+// Associated-const reference `C::DEFAULT_CAPACITY` on a bounded param.
+pub fn capacity_of<C: Container>() -> usize {
+    C::DEFAULT_CAPACITY
+}
@@ -0,0 +1,62 @@
+// Test fixture for associated-type projection in bounds and signatures.
+//
+// Associated types the index otherwise treats as opaque: `Iterator::Item`,
+// `Converter::Error`, `Database::Row: Iterator<Item = String>`, and bound
+// projections like `where P: Parser<'a, Output = T>` or `I::Item: Display`. Each
+// concrete impl maps its assoc-type names to concrete assignments, and projections
+// at use sites resolve through the governing impl.
+
+use std::fmt::Display;
+
+pub struct NumberIterator {
+    current: i32,
+    end: i32,
+}
+
+impl Iterator for NumberIterator {
+    type Item = i32; // NumberIterator::Item = i32
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current < self.end {
+            self.current += 1;
+            Some(self.current)
+        } else {
+            None
+        }
+    }
+}
+
+pub trait Converter {
+    type Error;
+    fn convert(&self, input: &str) -> Result<i32, Self::Error>;
+}
+
+pub trait Database {
+    // Associated type constrained to itself implement `Iterator`.
+    type Row: Iterator<Item = String>;
+    fn rows(&self) -> Self::Row;
+}
+
+pub trait Parser<'a> {
+    type Output;
+    fn parse(&self, input: &'a str) -> Self::Output;
+}
+
+// Bound projection `P: Parser<'a, Output = T>`.
+pub fn parse_with<'a, P, T>(parser: &P, input: &'a str) -> T
+where
+    P: Parser<'a, Output = T>,
+{
+    parser.parse(input)
+}
+
+// `I::Item: Display` projection bound.
+pub fn print_all<I>(iter: I)
+where
+    I: Iterator,
+    I::Item: Display,
+{
+    for item in iter {
+        println!("{}", item);
+    }
+}
@@ -0,0 +1,41 @@
+// Test fixture for associated-type projection resolution.
+//
+// Projection paths `<Type as Trait>::Assoc`, `T::Assoc`, and `Self::Assoc` must
+// resolve by locating the governing `impl Trait for Type` block and following its
+// `type Assoc = Concrete;` binding, falling back to the trait's associated-type
+// declaration (and any default) when the impl is generic or absent.
+
+/// Trait with associated input/output/error types (none of them defaulted).
+pub trait Converter {
+    type Input;
+    type Output;
+    type Error;
+
+    fn convert(&self, input: Self::Input) -> Result<Self::Output, Self::Error>;
+}
+
+pub struct IntConverter;
+
+impl Converter for IntConverter {
+    type Input = String;
+    type Output = i32;
+    type Error = String;
+
+    // `Self::Output` resolves to `i32`, `Self::Error` to `String`.
+    fn convert(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.parse::<i32>().map_err(|e| e.to_string())
+    }
+}
+
+/// `V::Output` on a bounded generic param projects through the impl binding.
+pub fn run_conversion<V>(v: &V, input: V::Input) -> Result<V::Output, V::Error>
+where
+    V: Converter,
+{
+    v.convert(input)
+}
+
+/// Fully-qualified `<IntConverter as Converter>::Output` projection.
+pub fn qualified_projection() -> <IntConverter as Converter>::Output {
+    IntConverter.convert("7".to_string()).unwrap()
+}
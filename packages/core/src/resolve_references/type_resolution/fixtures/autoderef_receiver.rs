@@ -0,0 +1,49 @@
+// Test fixture for autoderef receiver-adjustment in method resolution.
+//
+// Starting from the receiver type, build a chain by repeatedly stripping `&`/`&mut`
+// and applying `Deref::Target` of any `impl Deref`; at each step probe the
+// candidate-impl set (taking autoref `&self`/`&mut self` into account) and stop at
+// the first type that provides the method. The chain length is capped to guard
+// against cyclic `Deref` impls.
+
+use std::ops::Deref;
+
+pub trait Drawable {
+    fn draw(&self) -> String;
+}
+
+pub struct Canvas;
+
+impl Drawable for Canvas {
+    fn draw(&self) -> String {
+        "canvas".to_string()
+    }
+}
+
+// A smart pointer with an `impl Deref` whose target provides `draw`.
+pub struct Wrapper {
+    inner: Canvas,
+}
+
+impl Deref for Wrapper {
+    type Target = Canvas;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+pub fn via_box(boxed: Box<dyn Drawable>) -> String {
+    // `Box<dyn Drawable>` -> deref to `dyn Drawable`, which provides `draw`.
+    boxed.draw()
+}
+
+pub fn via_custom_deref(w: &Wrapper) -> String {
+    // `&Wrapper` -> strip ref -> Deref to `Canvas`, which provides `draw`.
+    w.draw()
+}
+
+pub fn via_reference(canvas: &&Canvas) -> String {
+    // Double reference -> strip twice before the inherent/trait method matches.
+    canvas.draw()
+}
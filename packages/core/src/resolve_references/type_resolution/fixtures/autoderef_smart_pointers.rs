@@ -0,0 +1,30 @@
+// Test fixture for auto-deref method resolution through Box/Rc/Arc/RefCell.
+//
+// When a receiver is `Box<T>`, `Rc<T>`, `Arc<T>`, or `&T`/`&mut T` and no
+// inherent/trait method matches on the wrapper, resolution recurses into `T` and
+// retries, recording the synthetic deref hop. `Mutex`/`RefCell` are handled
+// through the guard returned by `lock()`/`borrow_mut()` before the inner method
+// resolves.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+pub fn smart_pointer_method_chains() {
+    // `Box<String>` -> deref to `String`, which provides `len`.
+    let boxed_string: Box<String> = Box::new("hello".to_string());
+    let _len = boxed_string.len();
+
+    // `Rc<Vec<i32>>` via `as_ref()` -> `&Vec<i32>` -> `len`.
+    let rc_vec: Rc<Vec<i32>> = Rc::new(vec![1, 2, 3]);
+    let _rc_len = rc_vec.as_ref().len();
+
+    // `Arc<Mutex<HashMap>>` -> `lock().unwrap()` guard -> `insert`.
+    let locked_data: Arc<Mutex<std::collections::HashMap<String, i32>>> =
+        Arc::new(Mutex::new(std::collections::HashMap::new()));
+    locked_data.lock().unwrap().insert("a".to_string(), 1);
+
+    // `Rc<RefCell<Vec<i32>>>` -> `borrow_mut()` guard -> `push`.
+    let cell: Rc<RefCell<Vec<i32>>> = Rc::new(RefCell::new(Vec::new()));
+    cell.borrow_mut().push(42);
+}
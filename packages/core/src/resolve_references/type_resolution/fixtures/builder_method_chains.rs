@@ -0,0 +1,91 @@
+// Test fixture for type-directed resolution of builder-style method chains.
+//
+// Chained calls like `Config::new().host(..).port(..).enable_ssl().build()` need
+// the resolver to thread the type returned by each method into the next `.method()`
+// so every link resolves to the right impl. The same return-type tracking handles
+// `db.get_connection()` returning `Option<&mut Connection>` unwrapped into further
+// calls.
+
+pub struct Config;
+
+pub struct ConfigBuilder {
+    host: String,
+    port: u16,
+    ssl: bool,
+    timeout: u64,
+}
+
+impl Config {
+    pub fn new() -> ConfigBuilder {
+        ConfigBuilder {
+            host: String::new(),
+            port: 0,
+            ssl: false,
+            timeout: 30,
+        }
+    }
+}
+
+impl ConfigBuilder {
+    pub fn host(mut self, host: &str) -> Self {
+        self.host = host.to_string();
+        self
+    }
+
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    pub fn enable_ssl(mut self) -> Self {
+        self.ssl = true;
+        self
+    }
+
+    pub fn timeout(mut self, secs: u64) -> Self {
+        self.timeout = secs;
+        self
+    }
+
+    pub fn build(self) -> Config {
+        Config
+    }
+}
+
+pub struct Connection;
+
+impl Connection {
+    pub fn connect(&self) -> bool {
+        true
+    }
+
+    pub fn send_data(&mut self, _data: &[u8]) {}
+
+    pub fn close(self) {}
+}
+
+pub struct Database {
+    conn: Option<Connection>,
+}
+
+impl Database {
+    pub fn get_connection(&mut self) -> Option<&mut Connection> {
+        self.conn.as_mut()
+    }
+}
+
+pub fn build_config() -> Config {
+    Config::new()
+        .host("example.com")
+        .port(443)
+        .enable_ssl()
+        .timeout(60)
+        .build()
+}
+
+pub fn use_connection(db: &mut Database) {
+    if let Some(conn) = db.get_connection() {
+        conn.connect();
+        conn.send_data(&[1, 2, 3]);
+    }
+}
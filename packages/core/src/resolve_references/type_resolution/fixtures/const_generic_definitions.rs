@@ -0,0 +1,44 @@
+// Test fixture for const-generic params as first-class defs and references.
+//
+// A const generic param introduces a scope binding; every usage resolves back to
+// it — array type sizes (`[T; N]`, `[[T; COLS]; ROWS]`), const-expression bodies
+// (`N <= 10`, `N * M + 1`), turbofish arguments (`calculate_buffer_size::<N, M>()`,
+// `::<5, 10>()`), and associated-const initializers (`const DEFAULT_CAPACITY = N`).
+
+pub struct Buffer<T, const N: usize> {
+    data: [T; N],
+}
+
+impl<T: Copy + Default, const N: usize> Buffer<T, N> {
+    // `const DEFAULT_CAPACITY: usize = N;` — initializer references the param.
+    const DEFAULT_CAPACITY: usize = N;
+
+    pub fn new() -> Self {
+        Buffer {
+            data: [T::default(); N],
+        }
+    }
+
+    // Const expression body references `N`.
+    pub fn is_small() -> bool {
+        N <= 10
+    }
+
+    pub fn capacity() -> usize {
+        Self::DEFAULT_CAPACITY
+    }
+}
+
+// Free function with two const params used in a const-expression return.
+pub const fn calculate_buffer_size<const N: usize, const M: usize>() -> usize {
+    N * M + 1
+}
+
+pub fn use_turbofish() -> usize {
+    const N: usize = 5;
+    const M: usize = 10;
+    // Turbofish with local `const` items and with literal const args.
+    let a = calculate_buffer_size::<N, M>();
+    let b = calculate_buffer_size::<5, 10>();
+    a + b
+}
@@ -0,0 +1,43 @@
+// Test fixture for const generic parameters and const expressions in types.
+//
+// `Matrix<T, const ROWS: usize, const COLS: usize>` uses const generics in its
+// field type `[[T; COLS]; ROWS]`. Const params are their own symbol kind:
+// references to `ROWS`/`COLS` resolve to the param declaration, and const args at
+// use sites (`Matrix<T, COLS, OTHER_COLS>`) bind positionally to the right param
+// even when type and const params are interleaved.
+
+pub struct Matrix<T, const ROWS: usize, const COLS: usize> {
+    data: [[T; COLS]; ROWS],
+}
+
+impl<T: Copy + Default, const ROWS: usize, const COLS: usize> Matrix<T, ROWS, COLS> {
+    pub fn new() -> Self {
+        Matrix {
+            data: [[T::default(); COLS]; ROWS],
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        ROWS
+    }
+
+    pub fn cols(&self) -> usize {
+        COLS
+    }
+
+    // Method introducing its own const param, interleaved with the impl's.
+    pub fn multiply<const OTHER_COLS: usize>(
+        &self,
+        other: &Matrix<T, COLS, OTHER_COLS>,
+    ) -> Matrix<T, ROWS, OTHER_COLS> {
+        let _ = other;
+        Matrix::new()
+    }
+}
+
+pub fn build() -> Matrix<i32, 2, 3> {
+    let a: Matrix<i32, 2, 3> = Matrix::new();
+    let b: Matrix<i32, 3, 4> = Matrix::new();
+    let _product: Matrix<i32, 2, 4> = a.multiply(&b);
+    a
+}
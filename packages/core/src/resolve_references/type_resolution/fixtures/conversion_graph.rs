@@ -0,0 +1,53 @@
+// Test fixture for the From/Into/FromStr/parse conversion graph.
+//
+// `impl From<A> for B` records a directed conversion edge A -> B; `x.into()`,
+// `B::from(x)`, `a.parse::<T>()` (via `FromStr`), and `TryFrom`/`TryInto` sites
+// resolve to the concrete conversion impl. The subsystem can answer "what types
+// does T convert to/from".
+
+use std::str::FromStr;
+
+pub struct Celsius(pub f64);
+pub struct Fahrenheit(pub f64);
+
+// Edge: Celsius -> Fahrenheit.
+impl From<Celsius> for Fahrenheit {
+    fn from(c: Celsius) -> Self {
+        Fahrenheit(c.0 * 9.0 / 5.0 + 32.0)
+    }
+}
+
+// Edge: i32 -> Celsius.
+impl From<i32> for Celsius {
+    fn from(v: i32) -> Self {
+        Celsius(v as f64)
+    }
+}
+
+pub struct Port(pub u16);
+
+// FromStr edge used by `parse::<Port>()`.
+impl FromStr for Port {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<u16>().map(Port).map_err(|e| e.to_string())
+    }
+}
+
+// TryFrom edge: i64 -> Port (fallible).
+impl TryFrom<i64> for Port {
+    type Error = String;
+
+    fn try_from(v: i64) -> Result<Self, Self::Error> {
+        u16::try_from(v).map(Port).map_err(|e| e.to_string())
+    }
+}
+
+pub fn conversions() -> Result<(), String> {
+    let c = Celsius::from(20); // From::from
+    let _f: Fahrenheit = c.into(); // Into::into -> From impl
+    let _p: Port = "8080".parse::<Port>().map_err(|e| e)?; // FromStr
+    let _q = Port::try_from(443i64)?; // TryFrom
+    Ok(())
+}
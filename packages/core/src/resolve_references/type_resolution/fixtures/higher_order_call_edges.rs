@@ -0,0 +1,55 @@
+// Test fixture for higher-order call-graph edges.
+//
+// When a callable parameter (or struct field) is invoked inside a body and a
+// caller passes a concrete named function or closure at the call site, the
+// indexer synthesizes a call-graph edge from the invocation site through to the
+// passed callee. This propagates through struct fields too.
+
+fn concrete_transformer(s: &str) -> String {
+    s.to_uppercase()
+}
+
+// Invokes a callable parameter -> edge to whatever is passed at call sites.
+pub fn apply_transform<F>(transformer: F, input: &str) -> String
+where
+    F: Fn(&str) -> String,
+{
+    transformer(input)
+}
+
+// HRTB callable param.
+pub fn apply_hrtb<F>(processor: F) -> String
+where
+    F: for<'a> Fn(&'a str) -> &'a str,
+{
+    processor("slice").to_string()
+}
+
+// A boxed closure stored in a struct field and invoked in methods.
+pub struct GenericLifetimeStruct<'a> {
+    processor: Box<dyn Fn(&i32) -> String + 'a>,
+}
+
+impl<'a> GenericLifetimeStruct<'a> {
+    pub fn new(processor: Box<dyn Fn(&i32) -> String + 'a>) -> Self {
+        GenericLifetimeStruct { processor }
+    }
+
+    pub fn process(&self, item: &i32) -> String {
+        (self.processor)(item)
+    }
+
+    pub fn batch_process(&self, items: &[i32]) -> Vec<String> {
+        items.iter().map(|i| (self.processor)(i)).collect()
+    }
+}
+
+pub fn driver() -> String {
+    // Edge: apply_transform -> concrete_transformer.
+    let a = apply_transform(concrete_transformer, "hi");
+    // Edge: apply_transform -> the passed closure body.
+    let b = apply_transform(|s| s.to_lowercase(), &a);
+    // Edge propagates through the stored field into `process`.
+    let holder = GenericLifetimeStruct::new(Box::new(|n: &i32| n.to_string()));
+    holder.process(&7) + &b
+}
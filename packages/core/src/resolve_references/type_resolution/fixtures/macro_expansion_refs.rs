@@ -0,0 +1,35 @@
+// Test fixture for declarative-macro expansion discovering defs and refs.
+//
+// `create_processor!($name)` expands to `BaseProcessor::new($name.to_string(), 0)`.
+// Each invocation creates a real reference to `BaseProcessor::new`. The expansion
+// pass binds metavariables to the invocation token spans, substitutes them into
+// the transcriber, and re-runs reference extraction, recording provenance back to
+// both the macro definition and the invocation site.
+
+pub struct BaseProcessor {
+    pub name: String,
+    pub value: i32,
+}
+
+impl BaseProcessor {
+    pub fn new(name: String, value: i32) -> Self {
+        BaseProcessor { name, value }
+    }
+}
+
+// The macro whose transcriber references `BaseProcessor::new`.
+macro_rules! create_processor {
+    ($name:expr) => {
+        BaseProcessor::new($name.to_string(), 0)
+    };
+}
+
+// Invocation sites: after expansion each is a reference to `BaseProcessor::new`,
+// and the argument expression `label` must still resolve to the local binding.
+pub fn build_processors() -> Vec<BaseProcessor> {
+    let label = "worker";
+    vec![
+        create_processor!("primary"),
+        create_processor!(label),
+    ]
+}
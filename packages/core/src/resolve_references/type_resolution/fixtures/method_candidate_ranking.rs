@@ -0,0 +1,63 @@
+// Test fixture for trait-aware method resolution with candidate-impl ranking.
+//
+// Given a call-site receiver type, candidates are gathered as: (1) inherent impls
+// on the receiver type; (2) trait impls whose `Self` unifies with the receiver;
+// (3) for generic receivers bound by `T: Drawable + Describable`, the methods of
+// every bound trait; (4) for `Box<dyn Drawable>`/`&dyn Drawable`, every impl of
+// the trait (virtual-dispatch fan-out).
+
+pub trait Drawable {
+    fn draw(&self) -> String;
+}
+
+pub trait Describable {
+    fn describe(&self) -> String;
+}
+
+pub struct Circle {
+    radius: f64,
+}
+
+// Inherent impl (candidate set 1).
+impl Circle {
+    pub fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+}
+
+// Trait impls (candidate set 2).
+impl Drawable for Circle {
+    fn draw(&self) -> String {
+        format!("circle r={}", self.radius)
+    }
+}
+
+impl Describable for Circle {
+    fn describe(&self) -> String {
+        "a circle".to_string()
+    }
+}
+
+pub struct Square;
+
+impl Drawable for Square {
+    fn draw(&self) -> String {
+        "square".to_string()
+    }
+}
+
+// Generic receiver bounded by two traits (candidate set 3).
+pub fn render<T: Drawable + Describable>(shape: &T) -> String {
+    format!("{} ({})", shape.draw(), shape.describe())
+}
+
+// `&dyn Drawable` receiver -> fan out to every `impl Drawable` (candidate set 4).
+pub fn render_dyn(shape: &dyn Drawable) -> String {
+    shape.draw()
+}
+
+pub fn driver() -> String {
+    let c = Circle { radius: 1.0 };
+    let _a = c.area();
+    render(&c) + &render_dyn(&Square)
+}
@@ -0,0 +1,69 @@
+// Test fixture for resolving operator overloading to trait impl methods.
+//
+// Operators lower to their `std::ops`/`PartialEq`/`PartialOrd`/`Index` trait
+// methods: `+` -> `Add::add`, `[]` -> `Index::index`, `==` -> `PartialEq::eq`,
+// compound-assign `+=` -> `AddAssign::add_assign`, etc. Each operator occurrence
+// binds to the concrete `fn` when the operand type has a matching impl.
+
+use std::ops::{Add, AddAssign, Index, Mul};
+
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub struct Circle {
+    radius: f64,
+}
+
+impl Add for Circle {
+    type Output = Circle;
+
+    fn add(self, other: Circle) -> Circle {
+        Circle {
+            radius: self.radius + other.radius,
+        }
+    }
+}
+
+impl Mul<f64> for Circle {
+    type Output = Circle;
+
+    fn mul(self, scale: f64) -> Circle {
+        Circle {
+            radius: self.radius * scale,
+        }
+    }
+}
+
+impl AddAssign for Circle {
+    fn add_assign(&mut self, other: Circle) {
+        self.radius += other.radius;
+    }
+}
+
+pub struct Grid {
+    cells: Vec<i32>,
+}
+
+impl Index<usize> for Grid {
+    type Output = i32;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.cells[idx]
+    }
+}
+
+pub fn use_operators() -> f64 {
+    let a = Circle { radius: 1.0 };
+    let b = Circle { radius: 2.0 };
+
+    let mut summed = a + b; // Add::add
+    summed += a; // AddAssign::add_assign
+    let scaled = summed * 2.0; // Mul::mul
+    let _equal = a == b; // PartialEq::eq
+    let _ordered = a < b; // PartialOrd::lt
+
+    let grid = Grid {
+        cells: vec![10, 20, 30],
+    };
+    let _first = grid[0]; // Index::index
+
+    scaled.radius
+}
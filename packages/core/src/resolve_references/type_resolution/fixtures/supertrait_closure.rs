@@ -0,0 +1,57 @@
+// Test fixture for supertrait closure so inherited-method calls resolve.
+//
+// `Shape: Drawable + Describable` and `PrintableShape: Shape + Display + Debug`
+// mean an impl of the subtrait guarantees the supertrait methods exist. The
+// supertrait-closure pass computes the transitive supertrait set so a method call
+// on a subtrait-bounded value (or `&dyn Shape`) can resolve to any supertrait's
+// method (`area`, `draw`, `name`).
+
+use std::fmt::{Debug, Display};
+
+pub trait Drawable {
+    fn draw(&self) -> String;
+}
+
+pub trait Describable {
+    fn name(&self) -> String;
+}
+
+// Subtrait inheriting two supertraits.
+pub trait Shape: Drawable + Describable {
+    fn area(&self) -> f64;
+}
+
+// Deeper subtrait chain.
+pub trait PrintableShape: Shape + Display + Debug {}
+
+pub struct Circle {
+    radius: f64,
+}
+
+impl Drawable for Circle {
+    fn draw(&self) -> String {
+        "o".to_string()
+    }
+}
+
+impl Describable for Circle {
+    fn name(&self) -> String {
+        "circle".to_string()
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+}
+
+// Subtrait-bounded generic: `draw`/`name` (supertrait methods) must resolve.
+pub fn describe_shape<S: Shape>(shape: &S) -> String {
+    format!("{} {} {:.2}", shape.name(), shape.draw(), shape.area())
+}
+
+// `&dyn Shape` receiver: inherited methods resolvable through the supertrait set.
+pub fn describe_dyn(shape: &dyn Shape) -> String {
+    format!("{} {}", shape.draw(), shape.area())
+}
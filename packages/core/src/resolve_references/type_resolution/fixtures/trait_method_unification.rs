@@ -0,0 +1,54 @@
+// Test fixture for unification-based trait method dispatch.
+//
+// A method call on a trait-bounded generic (`processor.process(item)` where
+// `P: Service<Input = T, Output = String>`) must resolve to the concrete impl
+// body. The dispatch resolver collects candidate `impl Trait for Type` blocks
+// defining the method and unifies the receiver's (possibly generic) type against
+// each impl's `Self`, treating type parameters as placeholder holes.
+
+/// Trait whose method is dispatched to concrete impls.
+pub trait Service {
+    type Input;
+    type Output;
+
+    fn process(&self, input: Self::Input) -> Self::Output;
+}
+
+/// A concrete impl the dispatcher should unify `Self` against.
+pub struct DerivedProcessor;
+
+impl Service for DerivedProcessor {
+    type Input = i32;
+    type Output = String;
+
+    fn process(&self, input: Self::Input) -> Self::Output {
+        input.to_string()
+    }
+}
+
+/// A second impl to exercise candidate ranking among multiple `impl`s.
+pub struct PassthroughProcessor;
+
+impl Service for PassthroughProcessor {
+    type Input = String;
+    type Output = String;
+
+    fn process(&self, input: Self::Input) -> Self::Output {
+        input
+    }
+}
+
+/// Generic caller: `P` unifies with each candidate impl's `Self`.
+pub fn process_items<P, T>(processor: &P, items: Vec<T>) -> Vec<String>
+where
+    P: Service<Input = T, Output = String>,
+{
+    items
+        .into_iter()
+        .map(|item| processor.process(item))
+        .collect()
+}
+
+pub fn driver() -> Vec<String> {
+    process_items(&DerivedProcessor, vec![1, 2, 3])
+}
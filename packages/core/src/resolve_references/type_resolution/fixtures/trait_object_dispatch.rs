@@ -0,0 +1,55 @@
+// Test fixture for trait-method dispatch with trait objects, default methods,
+// and associated types.
+//
+// (1) A method call on a receiver of concrete type `C` selects the method from
+// `impl Trait for C`; (2) a `dyn Trait`/generic receiver fans out edges to every
+// known impl (virtual dispatch); (3) default-method bodies resolve self-calls
+// like `self.name()` to the trait decl.
+
+pub trait Drawable {
+    fn draw(&self) -> String;
+}
+
+pub trait Greet {
+    fn name(&self) -> String;
+
+    // Default method whose body calls another trait method on `self`.
+    fn hello(&self) -> String {
+        format!("hello, {}", self.name())
+    }
+}
+
+pub struct Circle;
+pub struct Square;
+
+impl Drawable for Circle {
+    fn draw(&self) -> String {
+        "circle".to_string()
+    }
+}
+
+impl Drawable for Square {
+    fn draw(&self) -> String {
+        "square".to_string()
+    }
+}
+
+impl Greet for Circle {
+    fn name(&self) -> String {
+        "circle".to_string()
+    }
+}
+
+// Concrete receiver -> single impl.
+pub fn draw_one(c: &Circle) -> String {
+    c.draw()
+}
+
+// `&[Box<dyn Drawable>]` -> fan out to every `impl Drawable`.
+pub fn draw_all(drawables: &[Box<dyn Drawable>]) -> Vec<String> {
+    drawables.iter().map(|d| d.draw()).collect()
+}
+
+pub fn greet(c: &Circle) -> String {
+    c.hello()
+}
@@ -0,0 +1,45 @@
+// Test fixture for type-flow tracking through `&mut Self` builder chains.
+//
+// Mirrors `constructor_workflow.rs`: `let mut product = Product::new(..)` then
+// `product.apply_discount(10.0).mark_out_of_stock()`. A `let` binds to the declared return type of its
+// constructing associated function (`Product::new -> Self` gives `product: Product`),
+// and each subsequent `.method()` resolves against the type carried forward from
+// the previous link's return type.
+
+pub struct Product {
+    name: String,
+    price: f64,
+    in_stock: bool,
+}
+
+impl Product {
+    // `-> Self` gives the binding its type.
+    pub fn new(name: &str, price: f64) -> Self {
+        Product {
+            name: name.to_string(),
+            price,
+            in_stock: true,
+        }
+    }
+
+    // `&mut Self` builder link — type flows onward unchanged.
+    pub fn apply_discount(&mut self, percent: f64) -> &mut Self {
+        self.price *= 1.0 - percent / 100.0;
+        self
+    }
+
+    pub fn mark_out_of_stock(&mut self) -> &mut Self {
+        self.in_stock = false;
+        self
+    }
+
+    pub fn summary(&self) -> String {
+        format!("{}: {:.2} ({})", self.name, self.price, self.in_stock)
+    }
+}
+
+pub fn configure() -> String {
+    let mut product = Product::new("widget", 100.0);
+    product.apply_discount(10.0).mark_out_of_stock();
+    product.summary()
+}
@@ -0,0 +1,42 @@
+// Test fixture for a where-clause bound environment.
+//
+// Each generic param maps to the set of traits it satisfies, collected from inline
+// bounds and the `where` clause. The dispatch resolver consults this environment
+// so a method/operator call on a value of generic type `T` resolves to the trait
+// method declared by one of `T`'s bounds, including `Fn`/`FnMut`/`FnOnce` bounds
+// for invoking bounded closure params.
+
+use std::fmt::Debug;
+
+pub trait Validator {
+    type Input;
+    fn validate(&self, input: &Self::Input) -> bool;
+}
+
+// `T: Clone + Debug + Send` -> method calls `.clone()` and `{:?}` resolve via bounds.
+pub fn complex_generic_function<T: Clone + Debug + Send>(value: T) -> String {
+    let cloned = value.clone();
+    format!("{:?}", cloned)
+}
+
+// `Fn` bound in a `where` clause: invoking the param resolves to the closure's call.
+pub fn process_with_closure<F>(processor: F, item: i32) -> i32
+where
+    F: Fn(i32) -> i32,
+{
+    processor(item)
+}
+
+// Bounds given only in the `where` clause, including an associated-type-constrained bound.
+pub fn ultimate_generic_function<T, V, F>(value: T, validator: V, mapper: F) -> Option<String>
+where
+    T: Clone + Debug,
+    V: Validator<Input = T>,
+    F: FnOnce(&T) -> String,
+{
+    if validator.validate(&value) {
+        Some(mapper(&value))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,60 @@
+// Future-producing classification and await-edge annotation.
+//
+// A function/closure is "future-producing" when it is `async fn`, an async
+// block/closure, returns `impl Future`/`Pin<Box<dyn Future<...>>>`, or is a type
+// with an `impl Future for` block. Each call edge is annotated with whether the
+// callee is awaited at the site (expression immediately followed by `.await`).
+
+use std::future::Future;
+use std::pin::Pin;
+
+// 1. Returns a boxed future without the `async` keyword.
+pub fn future_return_types() -> Pin<Box<dyn Future<Output = i32> + Send>> {
+    Box::pin(async { 42 })
+}
+
+// 2. Returns `impl Future`.
+pub fn impl_future_return() -> impl Future<Output = String> {
+    async { "hello".to_string() }
+}
+
+// 3. Recursive async via boxed future.
+pub fn async_recursive(n: u32) -> Pin<Box<dyn Future<Output = u32> + Send>> {
+    Box::pin(async move {
+        if n == 0 {
+            0
+        } else {
+            async_recursive(n - 1).await + n
+        }
+    })
+}
+
+// 4. A type that manually implements `Future`.
+pub struct CustomFuture {
+    done: bool,
+}
+
+impl Future for CustomFuture {
+    type Output = i32;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        if self.done {
+            std::task::Poll::Ready(7)
+        } else {
+            self.done = true;
+            std::task::Poll::Pending
+        }
+    }
+}
+
+// Call sites mixing awaited and non-awaited edges.
+pub async fn drive() -> i32 {
+    let awaited = future_return_types().await; // awaited edge
+    let _deferred = impl_future_return(); // not awaited at this site
+    let recursed = async_recursive(3).await; // awaited edge
+    let custom = CustomFuture { done: false }.await; // awaited edge
+    awaited + recursed + custom
+}
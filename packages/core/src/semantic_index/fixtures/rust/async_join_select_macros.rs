@@ -0,0 +1,49 @@
+// Call references hidden inside async control-flow macros.
+//
+// `join!`/`try_join!` take comma-separated future expressions; `select!` arms
+// have the shape `PATTERN = FUTURE_EXPR => HANDLER_EXPR`, where the pattern binds
+// names scoped to the handler. tree-sitter emits these as opaque token trees, so
+// the macro-aware handler must re-walk the argument stream for references and
+// introduce the select! arm bindings as locals.
+
+async fn first() -> i32 {
+    1
+}
+
+async fn second() -> i32 {
+    2
+}
+
+async fn third() -> Result<i32, String> {
+    Ok(3)
+}
+
+fn handle(value: i32) -> i32 {
+    value + 1
+}
+
+// 1. `join!` — each argument is a future expression to be walked.
+pub async fn join_usage() -> i32 {
+    let (a, b) = tokio::join!(first(), second());
+    a + b
+}
+
+// 2. `try_join!` — same, but fallible.
+pub async fn try_join_usage() -> Result<i32, String> {
+    let (a, b) = tokio::try_join!(third(), third())?;
+    Ok(a + b)
+}
+
+// 3. `select!` — `result` is bound per-arm and used in the handler expression.
+pub async fn select_usage() -> i32 {
+    tokio::select! {
+        result = first() => handle(result),
+        result = second() => handle(result),
+    }
+}
+
+// 4. `futures::join!` with a nested call inside an argument expression.
+pub async fn futures_join_usage() -> i32 {
+    let (a, b) = futures::join!(first(), async { handle(second().await) });
+    a + b
+}
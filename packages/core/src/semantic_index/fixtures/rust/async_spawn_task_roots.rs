@@ -0,0 +1,58 @@
+// Task-launching call sites whose block/closure arguments are call-graph roots.
+//
+// Every function invoked inside a spawned body (the `async move { ... }` passed
+// to `tokio::spawn` or the closure passed to `spawn_blocking`) must be reachable
+// from the call graph; today they look like dead code. This fixture isolates each
+// task-launching form so the call-graph builder can treat the argument as a root.
+
+use std::rc::Rc;
+use tokio::task::LocalSet;
+
+// Work that is only ever reached *through* a spawned task.
+async fn spawned_async_work(id: u32) -> u32 {
+    helper_in_task(id).await
+}
+
+async fn helper_in_task(id: u32) -> u32 {
+    id * 2
+}
+
+fn blocking_work(input: String) -> usize {
+    count_bytes(&input)
+}
+
+fn count_bytes(s: &str) -> usize {
+    s.len()
+}
+
+// 1. `tokio::spawn` with an async move block.
+pub fn spawn_task_root() {
+    tokio::spawn(async move {
+        let _ = spawned_async_work(7).await;
+    });
+}
+
+// 2. Fully-qualified `tokio::task::spawn`.
+pub fn spawn_fully_qualified() {
+    tokio::task::spawn(async move {
+        let _ = spawned_async_work(9).await;
+    });
+}
+
+// 3. `spawn_blocking` with a closure argument.
+pub fn spawn_blocking_root() {
+    tokio::task::spawn_blocking(move || blocking_work("payload".to_string()));
+}
+
+// 4. `!Send` form: `spawn_local` inside a `LocalSet`, capturing a non-Send `Rc`.
+pub async fn spawn_local_root() {
+    let local = LocalSet::new();
+    let shared = Rc::new(41u32);
+    local
+        .run_until(async move {
+            tokio::task::spawn_local(async move {
+                let _ = spawned_async_work(*shared).await;
+            });
+        })
+        .await;
+}
@@ -0,0 +1,53 @@
+// Closure-invocation edges through futures/stream combinator chains.
+//
+// Adapters like `map`, `then`, `for_each`, `filter`, `filter_map`, `and_then`,
+// and `fold` invoke the closure they are given; `buffer_unordered`/`buffered`
+// take no closure and drive the futures such closures return.
+// The combinator-awareness pass creates an "invokes" edge from the chain site to
+// the closure body so calls inside it (and inside any returned async block) reach
+// the call graph.
+
+use futures::stream::{self, StreamExt};
+
+async fn transform(x: i32) -> i32 {
+    double(x)
+}
+
+fn double(x: i32) -> i32 {
+    x * 2
+}
+
+fn keep_even(x: &i32) -> bool {
+    *x % 2 == 0
+}
+
+// 1. `map` returning an async block, then `buffer_unordered` + `collect`.
+pub async fn stream_example() -> Vec<i32> {
+    stream::iter(0..10)
+        .map(|x| async move { transform(x).await })
+        .buffer_unordered(3)
+        .collect()
+        .await
+}
+
+// 2. `filter` + `fold` with closures that call out.
+pub async fn complex_await_expressions() -> i32 {
+    stream::iter(0..10)
+        .filter(|x| {
+            let keep = keep_even(x);
+            async move { keep }
+        })
+        .fold(0, |acc, x| async move { acc + double(x) })
+        .await
+}
+
+// 3. `then`/`and_then`-style chaining over fallible items.
+pub async fn complex_error_handling() -> Result<Vec<i32>, String> {
+    stream::iter(0..5)
+        .then(|x| async move { transform(x).await })
+        .map(Ok::<i32, String>)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect()
+}
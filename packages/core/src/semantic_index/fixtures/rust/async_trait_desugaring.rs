@@ -0,0 +1,62 @@
+// `#[async_trait::async_trait]` desugaring for def/ref linking.
+//
+// The attribute rewrites each `async fn foo(&self) -> T` into
+// `fn foo(&self) -> Pin<Box<dyn Future<Output = T> + Send>>`. The resolver must
+// recognize the attribute on both trait and impl so the source `async fn` links
+// as a normal trait method: `impl.method()` binds to the trait def, and a
+// default-method override links impl-to-trait.
+
+#[async_trait::async_trait]
+pub trait AsyncProcessor {
+    async fn process(&self, item: String) -> String;
+
+    // Default method — an impl that does not override it still resolves here.
+    async fn process_default(&self, item: String) -> String {
+        self.process(item).await
+    }
+}
+
+pub struct AsyncImpl;
+
+#[async_trait::async_trait]
+impl AsyncProcessor for AsyncImpl {
+    async fn process(&self, item: String) -> String {
+        item.to_uppercase()
+    }
+
+    // Overrides the default — must link impl method back to the trait decl.
+    async fn process_default(&self, item: String) -> String {
+        self.process(item).await
+    }
+}
+
+// A second trait/impl pair that relies solely on the inherited default method.
+#[async_trait::async_trait]
+pub trait AsyncTrait {
+    async fn async_method(&self) -> i32;
+
+    async fn async_method_default(&self) -> i32 {
+        self.async_method().await + 1
+    }
+}
+
+pub struct AsyncImplInheriting;
+
+#[async_trait::async_trait]
+impl AsyncTrait for AsyncImplInheriting {
+    async fn async_method(&self) -> i32 {
+        42
+    }
+}
+
+// Call sites: each method call must resolve to the (desugared) trait method.
+pub async fn drive() -> String {
+    let async_impl = AsyncImpl;
+    let a = async_impl.process("x".to_string()).await;
+    let b = async_impl.process_default("y".to_string()).await;
+
+    let inheriting = AsyncImplInheriting;
+    let _ = inheriting.async_method_default().await;
+
+    format!("{a}{b}")
+}
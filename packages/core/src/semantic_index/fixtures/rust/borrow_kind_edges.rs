@@ -0,0 +1,39 @@
+// Borrow-kind classification on call-graph argument edges.
+//
+// Each argument position of a resolved call is annotated with `Move`,
+// `SharedBorrow`, `MutBorrow`, or `Copy`, derived from the argument expression:
+// a bare identifier of a non-`Copy` type is a `Move`, `&expr` is a `SharedBorrow`,
+// `&mut expr` is a `MutBorrow`, and a bare identifier of a `Copy` type is `Copy`.
+
+fn process_data(data: &Vec<i32>) -> usize {
+    data.len()
+}
+
+fn modify_data(data: &mut Vec<i32>) {
+    data.push(0);
+}
+
+fn consume_data(data: Vec<i32>) -> usize {
+    data.len()
+}
+
+fn take_scalar(n: i32) -> i32 {
+    n + 1
+}
+
+pub fn basic_borrowing_examples() {
+    let data = vec![1, 2, 3];
+    // SharedBorrow: `&data`.
+    let _len = process_data(&data);
+    // Copy: `count` is `i32`.
+    let count = 5;
+    let _next = take_scalar(count);
+}
+
+pub fn mutable_borrowing_examples() {
+    let mut data = vec![1, 2, 3];
+    // MutBorrow: `&mut data`.
+    modify_data(&mut data);
+    // Move: bare identifier of a non-Copy type, passed by value.
+    let _total = consume_data(data);
+}
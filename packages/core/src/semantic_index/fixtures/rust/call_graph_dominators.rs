@@ -0,0 +1,41 @@
+// Dominator-tree analysis over the call graph to find chokepoint functions.
+//
+// Supports a Cooper–Harvey–Kennedy iterative dominance computation: a function
+// dominates another if every path from the entry node must pass through it. This
+// fixture lays out a call graph with a clear chokepoint (`gateway`) that every
+// path from `entry` to the leaves must traverse, plus a diamond to exercise the
+// `intersect` fold over multiple predecessors.
+
+pub fn entry() {
+    // Both branches of the diamond funnel through `gateway`.
+    left();
+    right();
+}
+
+fn left() {
+    gateway();
+}
+
+fn right() {
+    gateway();
+}
+
+// Chokepoint: idom of everything below it; every path from `entry` hits it.
+fn gateway() {
+    worker_a();
+    worker_b();
+}
+
+fn worker_a() {
+    shared_leaf();
+}
+
+fn worker_b() {
+    shared_leaf();
+}
+
+// Immediate dominator is `gateway`: reached through either worker, so neither
+// worker dominates it.
+fn shared_leaf() {
+    // leaf
+}
@@ -0,0 +1,54 @@
+// Call-graph reachability (mark phase) for dead-code detection.
+//
+// A mark traversal from a configurable root set (e.g. `pub` API, `main`,
+// `#[test]` fns, or a seed like `demonstrate_all_features`) marks every callee
+// reachable via outgoing edges; any defined function left unmarked is reported as
+// unreachable. Trait methods invoked through dynamic dispatch (`Box<dyn Debug>`)
+// keep their candidate impls reachable.
+
+use std::fmt::Debug;
+
+// Seed entry point.
+pub fn demonstrate_all_features() {
+    reachable_a();
+    reachable_b();
+    create_test_scenarios();
+}
+
+fn reachable_a() {
+    reachable_shared();
+}
+
+fn reachable_b() {
+    reachable_shared();
+}
+
+fn reachable_shared() {
+    // leaf
+}
+
+// Reached only through dynamic dispatch on `Box<dyn Debug>`.
+fn create_test_scenarios() {
+    let items: Vec<Box<dyn Debug>> = vec![Box::new(Dispatched)];
+    for item in &items {
+        println!("{:?}", item);
+    }
+}
+
+struct Dispatched;
+
+impl Debug for Dispatched {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Kept reachable by the dynamic-dispatch call above.
+        write!(f, "Dispatched")
+    }
+}
+
+// --- Unreachable region: never called from any root. ---
+fn orphan_one() {
+    orphan_two();
+}
+
+fn orphan_two() {
+    // Only reachable from `orphan_one`, which no root reaches -> both dead.
+}
@@ -0,0 +1,58 @@
+// Completeness diagnostics for struct literals and trait impls.
+//
+// The indexing diagnostics subsystem emits structured findings: for a struct
+// literal, `MissingFields { ty, missing }` listing each absent declared field; for
+// an `impl Trait for Type`, the trait items left unimplemented (ignoring those
+// with defaults). This fixture intentionally contains both a complete and an
+// incomplete construction/impl so the diagnostics have something to report.
+
+pub struct Product {
+    pub name: String,
+    pub price: f64,
+    pub in_stock: bool,
+}
+
+pub fn complete_literal() -> Product {
+    Product {
+        name: "widget".to_string(),
+        price: 9.99,
+        in_stock: true,
+    }
+}
+
+// Missing the `in_stock` field -> MissingFields { ty: Product, missing: [in_stock] }.
+// (Retained as a fixture for the diagnostic; would not compile under rustc.)
+pub fn incomplete_literal(name: String, price: f64) -> Product {
+    Product { name, price }
+}
+
+pub trait Service {
+    fn process(&self, input: i32) -> i32;
+    fn name(&self) -> &str;
+
+    // Defaulted method — absence from an impl is not a missing item.
+    fn describe(&self) -> String {
+        format!("service {}", self.name())
+    }
+}
+
+pub struct CompleteService;
+
+impl Service for CompleteService {
+    fn process(&self, input: i32) -> i32 {
+        input
+    }
+
+    fn name(&self) -> &str {
+        "complete"
+    }
+}
+
+// Missing the required `name` method -> reported as an incomplete impl.
+pub struct IncompleteService;
+
+impl Service for IncompleteService {
+    fn process(&self, input: i32) -> i32 {
+        input * 2
+    }
+}
@@ -0,0 +1,55 @@
+// Enum-variant reference resolution and per-variant coverage queries.
+//
+// Variant paths in patterns (`Message::Move`, `Color::Rgb`,
+// `CompleteEnum::StructVariant`) resolve as references to the variant definitions
+// rather than opaque path segments. A coverage query reports, per variant, every
+// `match`/`if let`/`while let` site matching it, flagging variants constructed but
+// never matched (dead-on-arrival) or matched but never constructed.
+
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+}
+
+pub enum Color {
+    Rgb(u8, u8, u8),
+    Gray(u8),
+}
+
+pub enum CompleteEnum {
+    Unit,
+    Tuple(i32),
+    StructVariant { id: u32 },
+}
+
+// Construction sites (references to the variant definitions).
+pub fn build() -> (Message, Color, CompleteEnum) {
+    (
+        Message::Move { x: 1, y: 2 },
+        Color::Rgb(255, 0, 0),
+        CompleteEnum::StructVariant { id: 7 },
+    )
+}
+
+// Match sites — each arm is a reference to the named variant.
+pub fn classify(msg: &Message) -> &'static str {
+    match msg {
+        Message::Quit => "quit",
+        Message::Move { .. } => "move",
+        Message::Write(_) => "write",
+    }
+}
+
+pub fn is_red(color: &Color) -> bool {
+    if let Color::Rgb(r, _, _) = color {
+        *r == 255
+    } else {
+        false
+    }
+}
+
+// `Color::Gray` is constructed nowhere above -> matched-but-never-constructed.
+pub fn is_gray(color: &Color) -> bool {
+    matches!(color, Color::Gray(_))
+}
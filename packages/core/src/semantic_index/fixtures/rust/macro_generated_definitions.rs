@@ -0,0 +1,58 @@
+// macro_rules! expansion so generated definitions appear in the scope graph.
+//
+// Items defined purely through declarative macros — `create_struct!(Point, ..)`,
+// `create_enum!(Color { .. })`, and the `property!` getter/setter generator —
+// must become resolvable definitions (`Point`, `Color::Red`, `get_name`) with
+// provenance pointing at both the macro definition and the invocation site, so
+// references to them no longer dangle.
+
+macro_rules! create_struct {
+    ($name:ident, $($field:ident : $ty:ty),* $(,)?) => {
+        pub struct $name {
+            $(pub $field: $ty),*
+        }
+    };
+}
+
+macro_rules! create_enum {
+    ($name:ident { $($variant:ident),* $(,)? }) => {
+        pub enum $name {
+            $($variant),*
+        }
+    };
+}
+
+macro_rules! property {
+    ($field:ident, $ty:ty, $getter:ident, $setter:ident) => {
+        pub fn $getter(&self) -> &$ty {
+            &self.$field
+        }
+
+        pub fn $setter(&mut self, value: $ty) {
+            self.$field = value;
+        }
+    };
+}
+
+// Expansions introduce `Point`, `Color`/`Color::Red`, and the accessor fns.
+create_struct!(Point, x: f64, y: f64);
+create_enum!(Color { Red, Green, Blue });
+
+pub struct Named {
+    name: String,
+}
+
+impl Named {
+    property!(name, String, get_name, set_name);
+}
+
+pub fn use_generated() -> f64 {
+    let p = Point { x: 1.0, y: 2.0 };
+    let _c = Color::Red;
+    let mut n = Named {
+        name: "a".to_string(),
+    };
+    n.set_name("b".to_string());
+    let _got = n.get_name();
+    p.x + p.y
+}
@@ -0,0 +1,58 @@
+// Match exhaustiveness and unreachable-arm diagnostics.
+//
+// Over the parsed match arms, a usefulness predicate `U(P, p)` drives two
+// diagnostics: "non-exhaustive patterns: `X` not covered" and "unreachable arm".
+// This fixture pairs exhaustive matches with intentionally non-exhaustive and
+// dead-arm matches so the algorithm has both positive and negative cases.
+
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(u8, u8, u8),
+}
+
+// Exhaustive: every variant covered, no wildcard needed.
+pub fn handle_message(msg: Message) -> String {
+    match msg {
+        Message::Quit => "quit".to_string(),
+        Message::Move { x, y } => format!("move {x},{y}"),
+        Message::Write(text) => text,
+        Message::ChangeColor(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+    }
+}
+
+// Exhaustive over a bounded integer via ranges + wildcard.
+pub fn exhaustive_match(n: u8) -> &'static str {
+    match n {
+        0 => "zero",
+        1..=9 => "small",
+        _ => "large",
+    }
+}
+
+// Slice patterns covering empty / single / many.
+pub fn analyze_slice(items: &[i32]) -> &'static str {
+    match items {
+        [] => "empty",
+        [_] => "one",
+        [_, _, ..] => "many",
+    }
+}
+
+// Non-exhaustive: `ChangeColor` is not covered (diagnostic target).
+pub fn non_exhaustive(msg: Message) -> String {
+    match msg {
+        Message::Quit => "quit".to_string(),
+        Message::Move { .. } => "move".to_string(),
+        Message::Write(text) => text,
+    }
+}
+
+// Unreachable arm: the wildcard makes the trailing literal dead.
+pub fn unreachable_arm(n: i32) -> &'static str {
+    match n {
+        _ => "any",
+        0 => "zero",
+    }
+}
@@ -0,0 +1,39 @@
+// Structured extraction of sub-patterns as a nested pattern tree.
+//
+// Deeply nested destructuring (`Some(Ok(value))`, `Ok(Person { age: Some(age), .. })`,
+// `Some(Some((x, y)))`) is attached to each arm as a pattern tree: every node
+// records its constructor (variant/tuple/struct/slice), its sub-patterns, and —
+// for struct patterns — the field name each sub-pattern binds against, including
+// `..` rest markers.
+
+pub struct Person {
+    name: String,
+    age: Option<u32>,
+}
+
+// Constructor(Some) -> Constructor(Ok) -> Binding(value).
+pub fn unwrap_nested(input: Option<Result<i32, String>>) -> i32 {
+    match input {
+        Some(Ok(value)) => value,
+        Some(Err(_)) => -1,
+        None => 0,
+    }
+}
+
+// Struct sub-pattern with a field path `age -> Some(age)` and a `..` rest marker.
+pub fn person_age(result: Result<Person, String>) -> u32 {
+    match result {
+        Ok(Person { age: Some(age), .. }) => age,
+        Ok(Person { age: None, .. }) => 0,
+        Err(_) => 0,
+    }
+}
+
+// Tuple nested inside two Option constructors: Some -> Some -> Tuple(x, y).
+pub fn nested_tuple(input: Option<Option<(i32, i32)>>) -> i32 {
+    match input {
+        Some(Some((x, y))) => x + y,
+        Some(None) => 0,
+        None => -1,
+    }
+}
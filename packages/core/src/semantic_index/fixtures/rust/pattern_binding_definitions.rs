@@ -0,0 +1,55 @@
+// Promote pattern-introduced bindings to first-class definitions.
+//
+// Bindings created inside patterns each become a definition node scoped to the
+// correct arm/block so go-to-definition, find-references, and rename work:
+// struct field shorthand (`Point { x, y }`), `@` captures, slice rest bindings
+// (`rest @ ..`), `ref`/`ref mut`, tuple/struct destructuring in `let`/params, and
+// `let ... else` bindings.
+
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub enum Color {
+    Rgb(u8, u8, u8),
+    Named(String),
+}
+
+// Struct destructuring in a function parameter.
+pub fn parameter_destructuring(Point { x, y }: Point) -> i32 {
+    x + y
+}
+
+pub fn pattern_bindings(color: Color, values: &[i32]) -> i32 {
+    // `@` capture over a range.
+    let classified = match 15 {
+        coord @ 10..=20 => coord,
+        other => other,
+    };
+
+    // `@` capture binding the whole value alongside a variant match.
+    let named = match color {
+        color @ Color::Rgb(..) => format!("{:?}", matches!(color, Color::Rgb(..))),
+        Color::Named(name) => name,
+    };
+
+    // Slice rest binding `rest @ ..`.
+    let rest_len = match values {
+        [first, rest @ ..] => first + rest.len() as i32,
+        [] => 0,
+    };
+
+    // `ref mut` binding inside an `if let`.
+    let mut maybe = Some("text".to_string());
+    if let Some(ref mut text) = maybe {
+        text.push('!');
+    }
+
+    // `let ... else` binding.
+    let Some(unwrapped) = maybe else {
+        return classified;
+    };
+
+    classified + rest_len + unwrapped.len() as i32 + named.len() as i32
+}
@@ -0,0 +1,44 @@
+// Rc/Arc reference-cycle detection via shared-ownership edges.
+//
+// When a field or local of type `Rc<T>`/`Arc<T>` stores a value that transitively
+// (through `RefCell`/`Vec`/struct fields) can reach a value of the containing
+// type, flag a potential strong reference cycle. Edges through `Weak<T>` (from
+// `Rc::downgrade`) are marked non-owning and break the cycle.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// Parent holds strong refs to children; each child holds a `Weak` back-edge.
+pub struct Node {
+    // Strong ownership edge Node -> Node (through RefCell<Vec<Rc<..>>>).
+    children: RefCell<Vec<Rc<Node>>>,
+    // Non-owning back-edge: does not contribute to a strong cycle.
+    parent: RefCell<Weak<Node>>,
+    value: i32,
+}
+
+impl Node {
+    pub fn new(value: i32) -> Rc<Node> {
+        Rc::new(Node {
+            children: RefCell::new(Vec::new()),
+            parent: RefCell::new(Weak::new()),
+            value,
+        })
+    }
+}
+
+pub fn combined_smart_pointer_examples() {
+    let parent = Node::new(0);
+    let child = Node::new(1);
+
+    // Strong edge parent -> child.
+    parent.children.borrow_mut().push(Rc::clone(&child));
+    // Weak (non-owning) edge child -> parent via downgrade.
+    *child.parent.borrow_mut() = Rc::downgrade(&parent);
+
+    // A deliberate strong cycle: both directions strong -> flagged.
+    let a = Node::new(10);
+    let b = Node::new(20);
+    a.children.borrow_mut().push(Rc::clone(&b));
+    b.children.borrow_mut().push(Rc::clone(&a));
+}
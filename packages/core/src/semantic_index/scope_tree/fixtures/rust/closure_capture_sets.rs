@@ -0,0 +1,38 @@
+// Explicit closure capture sets with capture-mode classification.
+//
+// Each closure records exactly which outer bindings it captures and the mode:
+// `ByRef`, `ByMutRef`, `ByValue`, or explicit `Move`. The mode is inferred by
+// resolving every free name in the closure body to an enclosing binding and
+// looking at how it is used (read -> ByRef, mutated -> ByMutRef, moved/`move` ->
+// ByValue).
+
+fn helper(x: i32) -> i32 {
+    x + 1
+}
+
+pub fn capture_examples() {
+    let data = vec![1, 2, 3];
+    let mut count = 0;
+    let label = "items".to_string();
+
+    // ByRef: reads `data` only.
+    let read_only = || data.len();
+    let _ = read_only();
+
+    // ByMutRef: mutates captured `count`.
+    let mut increment = || {
+        count += 1;
+    };
+    increment();
+
+    // Move (explicit): takes ownership of `label`.
+    let owns = move || format!("{}: done", label);
+    let _ = owns();
+
+    // Nested closure calling a free function `helper`.
+    let nested = |seed: i32| {
+        let inner = move |n: i32| helper(n) + seed;
+        inner(10)
+    };
+    let _ = nested(5);
+}
@@ -0,0 +1,58 @@
+// Const-generic params and associated types in the type namespace.
+//
+// `const N: usize` is a value-namespace binding visible in array-length and other
+// const positions; trait associated types (`type Item`/`type Error`) and GATs
+// (`type Borrowed<'a> where Self: 'a`) are type-namespace members of the trait/impl
+// scope; `Self::Item`/`U::Error` qualified paths resolve the qualifier then look up
+// the member in that item's scope.
+
+// Const generic `N` used in the array length `[T; N]`.
+pub struct FixedArray<T, const N: usize> {
+    data: [T; N],
+}
+
+impl<T: Copy + Default, const N: usize> FixedArray<T, N> {
+    pub fn new() -> Self {
+        FixedArray {
+            data: [T::default(); N],
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+}
+
+pub trait Producer {
+    type Item;
+    type Error;
+
+    // GAT: associated type carrying its own lifetime parameter.
+    type Borrowed<'a>
+    where
+        Self: 'a;
+
+    fn produce(&self) -> Result<Self::Item, Self::Error>;
+    fn borrow_item<'a>(&'a self) -> Self::Borrowed<'a>;
+}
+
+pub struct StringProducer;
+
+impl Producer for StringProducer {
+    type Item = String;
+    type Error = String;
+    type Borrowed<'a> = &'a str;
+
+    fn produce(&self) -> Result<Self::Item, Self::Error> {
+        Ok("produced".to_string())
+    }
+
+    fn borrow_item<'a>(&'a self) -> Self::Borrowed<'a> {
+        "borrowed"
+    }
+}
+
+// `U::Item`/`U::Error` projections on a bounded type parameter.
+pub fn run<U: Producer>(u: &U) -> Result<U::Item, U::Error> {
+    u.produce()
+}
@@ -0,0 +1,37 @@
+// Extract-function analysis driven by the scope graph.
+//
+// Given a contiguous run of statements inside a function body (e.g. the
+// `for item in items { ... }` block in `process_data`), the analysis computes
+// inputs (references resolving to locals defined before the range, marked
+// `&mut`/`&`/by-value by mutation/move) and outputs (locals defined in-range and
+// referenced after it).
+
+pub fn process_data(items: Vec<i32>) -> (i32, Vec<i32>) {
+    let factor = 3; // input to the selection (read-only -> `&`/by-value)
+    let mut doubled = Vec::new(); // input, mutated in range -> `&mut`
+
+    // --- selection start: a candidate extraction range ---
+    let mut sum = 0; // defined in range, used after -> output
+    for item in items {
+        let scaled = item * factor;
+        sum += scaled;
+        doubled.push(scaled);
+    }
+    // --- selection end ---
+
+    // `sum` is defined in the selection and live after it -> output; `doubled`
+    // is live too but defined before the selection, so it stays an `&mut` input.
+    (sum, doubled)
+}
+
+// A second shape: the selection moves an owned value, so the input is by-value.
+pub fn consume_in_range(buffer: String) -> usize {
+    let prefix = "log: ".to_string();
+
+    // --- selection start ---
+    let combined = [prefix, buffer].concat(); // moves `buffer`
+    let len = combined.len(); // output
+    // --- selection end ---
+
+    len
+}
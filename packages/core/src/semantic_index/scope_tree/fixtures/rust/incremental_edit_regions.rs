@@ -0,0 +1,38 @@
+// Incremental scope-graph update surface.
+//
+// For editor/REPL consumers that re-evaluate a buffer on every keystroke, a full
+// reparse per change is wasteful on large files. The incremental path takes a
+// tree-sitter edit (old tree + byte range + new text), reuses the incremental
+// parse, and recomputes only the scope-graph regions whose covering syntax nodes
+// changed — invalidating and re-resolving just the affected scopes and the
+// cross-scope references pointing into them, leaving untouched defs intact.
+//
+// This fixture provides several independent top-level scopes so an edit confined
+// to one function exercises region-local invalidation (only that function's
+// scope and references into it are recomputed).
+
+const SHARED_CONSTANT: i32 = 100;
+
+// Edit target A: a self-contained function; editing its body must not touch B/C.
+pub fn region_a(input: i32) -> i32 {
+    let local = input + SHARED_CONSTANT;
+    let doubled = local * 2;
+    helper(doubled)
+}
+
+// Edit target B: references `region_a`, so edits to A's signature invalidate the
+// cross-scope reference here but not B's own local definitions.
+pub fn region_b() -> i32 {
+    let seed = 7;
+    region_a(seed)
+}
+
+// Edit target C: fully independent; never recomputed for edits in A or B.
+pub fn region_c() -> String {
+    let parts = vec!["a", "b", "c"];
+    parts.join("-")
+}
+
+fn helper(value: i32) -> i32 {
+    value.saturating_add(1)
+}
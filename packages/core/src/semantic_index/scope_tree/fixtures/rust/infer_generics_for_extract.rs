@@ -0,0 +1,28 @@
+// Infer generic parameters and lifetimes needed by a synthesized signature.
+//
+// For a set of input/output bindings, determine which generic type params and
+// named lifetimes an extracted standalone function must name: walk each binding's
+// resolved type, and whenever it mentions a generic param or named lifetime in
+// scope at the selection but not concrete, collect it into the synthesized
+// signature's parameter list, preserving the bounds recorded on the enclosing
+// `fn`/`impl`.
+
+use std::fmt::Debug;
+
+// Enclosing fn declares `T: Clone + Debug` and lifetime `'a`; a region that uses
+// `value: &'a T` must carry both `T` (with its bounds) and `'a` into the extract.
+pub fn enclosing<'a, T: Clone + Debug>(value: &'a T, label: &'a str) -> String {
+    // --- region referencing `value` (&'a T) and `label` (&'a str) ---
+    let cloned = value.clone();
+    let rendered = format!("{}: {:?}", label, cloned);
+    // --- end region: synthesized sig needs <'a, T: Clone + Debug> ---
+    rendered
+}
+
+// Const generic `N` in scope is likewise collected when the region touches `[u8; N]`.
+pub fn enclosing_const<const N: usize>(buffer: [u8; N]) -> usize {
+    // --- region referencing `buffer: [u8; N]` -> needs <const N: usize> ---
+    let len = buffer.len();
+    // --- end region ---
+    len
+}
@@ -0,0 +1,55 @@
+// Lifetime parameters and loop labels as first-class symbols.
+//
+// A lifetime introduced by a generic param list, an `impl` header, or a
+// `for<'a>` quantifier is a definition; every `&'a`, `<'a>`, and outlives bound
+// `'b: 'a` resolves to its introducer. HRTB opens a fresh nested lifetime scope
+// that shadows outer ones. Loop labels (`'outer`) are definitions referenced by
+// `break 'outer` / `continue 'outer`.
+
+// One lifetime `'a` shared by two reference fields.
+pub struct Container<'a, T, U> {
+    first: &'a T,
+    second: &'a U,
+}
+
+// Multiple lifetimes with outlives bounds `'b: 'a` and `'c: 'b`.
+pub struct BorrowChecker<'a, 'b, 'c>
+where
+    'b: 'a,
+    'c: 'b,
+{
+    short: &'a str,
+    mid: &'b str,
+    long: &'c str,
+}
+
+impl<'a, T, U> Container<'a, T, U> {
+    pub fn first(&self) -> &'a T {
+        self.first
+    }
+}
+
+// HRTB: `for<'a>` opens a nested lifetime scope that shadows any outer `'a`.
+pub fn apply_hrtb<F>(f: F) -> String
+where
+    F: for<'a> Fn(&'a str) -> &'a str,
+{
+    f("input").to_string()
+}
+
+// Loop labels: `'outer` and `'inner` are definitions; break/continue reference them.
+pub fn labelled_loops() -> i32 {
+    let mut total = 0;
+    'outer: for i in 0..10 {
+        'inner: for j in 0..10 {
+            if i * j > 20 {
+                break 'outer;
+            }
+            if j == 5 {
+                continue 'inner;
+            }
+            total += 1;
+        }
+    }
+    total
+}
@@ -0,0 +1,45 @@
+// Macro definition/invocation resolution with textual scoping.
+//
+// `macro_rules!` items are definitions in a macro namespace; `name!(...)`,
+// `name![...]`, `name!{...}` invocations are references. `macro_rules` textual
+// scoping means a macro is only visible after its definition within the same
+// scope chain, unless `#[macro_export]`/`#[macro_use]` lifts it to crate scope.
+// Identifiers inside invocation argument expressions still resolve against the
+// surrounding scope.
+
+// Exported macro — visible crate-wide regardless of textual position.
+#[macro_export]
+macro_rules! debug_scope {
+    ($e:expr) => {{
+        let __value = $e;
+        println!("{:?}", __value);
+        __value
+    }};
+}
+
+// Locally-scoped macro: only visible textually after this point.
+macro_rules! square {
+    ($x:expr) => {
+        $x * $x
+    };
+}
+
+pub fn test_macro() -> i32 {
+    let base = 8;
+    // Reference to `debug_scope!`; the argument `42 + base` must resolve `base`.
+    let logged = debug_scope!(42 + base);
+    // Reference to the textually-earlier local macro `square!`.
+    let squared = square!(base);
+    logged + squared
+}
+
+// A macro defined *after* a function cannot be referenced from before it.
+pub fn uses_later_macro() -> i32 {
+    later_only!(3)
+}
+
+macro_rules! later_only {
+    ($n:expr) => {
+        $n + 1
+    };
+}
@@ -0,0 +1,36 @@
+// Qualified scope-path call hierarchy across nested functions and closures.
+//
+// Mirrors the `nested_scopes` fixture's `enclosing_function_scope_id`: calls to
+// `helper()` happen directly in a top-level function, at block scope, inside nested functions, and
+// inside a closure. The call-hierarchy API returns incoming/outgoing calls where
+// each call site carries its fully qualified enclosing-scope path, e.g.
+// `outer_function::inner_function::deeper_function` or `outer_function::{closure@L..}`.
+
+pub fn helper() -> i32 {
+    42
+}
+
+// Call directly in a top-level function body: path `call_at_top`.
+pub fn call_at_top() -> i32 {
+    helper()
+}
+
+pub fn outer_function() -> i32 {
+    // Call at block scope inside `outer_function`.
+    let from_block = {
+        helper()
+    };
+
+    fn inner_function() -> i32 {
+        fn deeper_function() -> i32 {
+            // Path: outer_function::inner_function::deeper_function.
+            helper()
+        }
+        deeper_function()
+    }
+
+    // Call inside a closure: path `outer_function::{closure@..}`.
+    let in_closure = || helper();
+
+    from_block + inner_function() + in_closure()
+}
@@ -0,0 +1,37 @@
+// Data-flow "region summary" API for extract-function refactoring.
+//
+// Given a byte range selecting a run of statements inside a function body (as in
+// `outer_function`/`higher_order_examples`), compute a structured summary:
+// (a) inputs — locals declared outside the range but referenced inside;
+// (b) outputs — locals declared inside the range that are live afterwards;
+// (c) whether control flow leaves the range (early return/break), reusing the
+// existing binding/reference resolution.
+
+pub fn outer_function(items: Vec<i32>) -> i32 {
+    let threshold = 5; // input
+    let mut accumulator = 0; // input (mutated in range)
+
+    // --- region start ---
+    let mut seen = 0; // defined in range, live after -> output
+    for item in &items {
+        if *item > threshold {
+            accumulator += item;
+            seen += 1;
+        }
+    }
+    // --- region end ---
+
+    accumulator + seen
+}
+
+pub fn higher_order_examples() -> i32 {
+    let base = 10; // input to the region below
+    let multiplier = 3; // input
+
+    // --- region start (closure-bearing) ---
+    let transform = |x: i32| x * multiplier + base; // used only in range -> not an output
+    let result = transform(4); // output
+    // --- region end ---
+
+    result
+}
@@ -0,0 +1,61 @@
+// Local type-flow narrowing for dynamic-dispatch call resolution.
+//
+// When the concrete type reaching a `&dyn Handler` receiver is actually known, a
+// forward type-propagation pass narrows the call to the single responsible impl
+// instead of fanning out to all of them. Concrete types are tracked from
+// constructors (`HandlerA::new()`), from function returns whose declared type is
+// concrete or `impl Trait`, and through simple `let` rebindings.
+
+pub trait Handler {
+    fn handle(&self) -> i32;
+}
+
+pub struct HandlerA;
+pub struct HandlerB;
+pub struct HandlerC;
+
+impl HandlerA {
+    pub fn new() -> Self {
+        HandlerA
+    }
+}
+
+impl Handler for HandlerA {
+    fn handle(&self) -> i32 {
+        1
+    }
+}
+
+impl Handler for HandlerB {
+    fn handle(&self) -> i32 {
+        2
+    }
+}
+
+impl Handler for HandlerC {
+    fn handle(&self) -> i32 {
+        3
+    }
+}
+
+fn make_b() -> HandlerB {
+    HandlerB
+}
+
+// Narrowed: concrete `HandlerA` flows to the receiver -> resolves to HandlerA::handle.
+pub fn narrowed_constructor() -> i32 {
+    let h = HandlerA::new();
+    h.handle()
+}
+
+// Narrowed through a `let` rebinding of a concrete function return.
+pub fn narrowed_rebind() -> i32 {
+    let first = make_b();
+    let aliased = first;
+    aliased.handle()
+}
+
+// Not narrowable: the concrete type is unknown behind `&dyn Handler` -> fan out.
+pub fn fan_out(handler: &dyn Handler) -> i32 {
+    handler.handle()
+}